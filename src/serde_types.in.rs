@@ -1,11 +1,46 @@
-use serde::{Serialize, Serializer};
+use serde::{Serialize, Serializer, Deserialize, Deserializer};
+use serde::de::Error as DeError;
 use chrono::NaiveDateTime;
+use std::convert::{TryFrom, TryInto};
+use std::fmt;
+use std::error::Error as StdError;
 
 /// Representation of any text sent through slack
 /// the text must be processed to escape specific characters
-#[derive(Serialize, Debug, Default, Clone)]
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
 pub struct SlackText(String);
 
+impl SlackText {
+    /// Build a new `SlackText`, escaping the characters Slack's `mrkdwn`
+    /// engine treats specially (`&`, `<`, `>`).
+    pub fn new<S: Into<String>>(text: S) -> SlackText {
+        let escaped = text.into()
+            .replace('&', "&amp;")
+            .replace('<', "&lt;")
+            .replace('>', "&gt;");
+        SlackText(escaped)
+    }
+
+    /// Build a new `SlackText` without escaping, for callers who have
+    /// already composed `mrkdwn` markup (e.g. `*bold*` or `<url|label>`)
+    /// and don't want it mangled.
+    pub fn new_unescaped<S: Into<String>>(text: S) -> SlackText {
+        SlackText(text.into())
+    }
+}
+
+impl<'a> From<&'a str> for SlackText {
+    fn from(text: &'a str) -> SlackText {
+        SlackText::new(text)
+    }
+}
+
+impl From<String> for SlackText {
+    fn from(text: String) -> SlackText {
+        SlackText::new(text)
+    }
+}
+
 /// A `HexColor` `String` can be one of:
 ///
 /// 1. `String`s: `good`, `warning`, `danger`
@@ -15,9 +50,77 @@ pub struct SlackText(String);
 #[derive(Serialize, Debug)]
 pub struct HexColor(String);
 
+impl HexColor {
+    /// Keywords Slack accepts in place of a hex color code.
+    const KEYWORDS: [&'static str; 3] = ["good", "warning", "danger"];
+
+    fn is_valid(s: &str) -> bool {
+        if Self::KEYWORDS.contains(&s) {
+            return true;
+        }
+        let hex = s.trim_start_matches('#');
+        (hex.len() == 3 || hex.len() == 6) && hex.chars().all(|c| c.is_ascii_hexdigit())
+    }
+}
+
+impl<'de> Deserialize<'de> for HexColor {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<Self, D::Error>
+        where D: Deserializer<'de>
+    {
+        let s = String::deserialize(deserializer)?;
+        if HexColor::is_valid(&s) {
+            Ok(HexColor(s))
+        } else {
+            Err(DeError::custom(format!("invalid hex color: {}", s)))
+        }
+    }
+}
+
+/// Error returned when a string is not a valid `HexColor`.
+#[derive(Debug, Clone)]
+pub struct HexColorError(String);
+
+impl fmt::Display for HexColorError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "invalid hex color: {}", self.0)
+    }
+}
+
+impl StdError for HexColorError {}
+
+impl From<::std::convert::Infallible> for HexColorError {
+    fn from(never: ::std::convert::Infallible) -> HexColorError {
+        match never {}
+    }
+}
+
+impl<'a> TryFrom<&'a str> for HexColor {
+    type Error = HexColorError;
+
+    fn try_from(s: &'a str) -> ::std::result::Result<Self, Self::Error> {
+        if HexColor::is_valid(s) {
+            Ok(HexColor(s.to_string()))
+        } else {
+            Err(HexColorError(s.to_string()))
+        }
+    }
+}
+
+impl TryFrom<String> for HexColor {
+    type Error = HexColorError;
+
+    fn try_from(s: String) -> ::std::result::Result<Self, Self::Error> {
+        if HexColor::is_valid(&s) {
+            Ok(HexColor(s))
+        } else {
+            Err(HexColorError(s))
+        }
+    }
+}
+
 /// Slack allows for attachments to be added to messages. See
 /// https://api.slack.com/docs/attachments for more information.
-#[derive(Serialize, Debug, Default)]
+#[derive(Serialize, Deserialize, Debug, Default)]
 pub struct Attachment {
     /// Required text for attachment.
     /// Slack will use this text to display on devices that don't support markup.
@@ -69,6 +172,351 @@ pub struct Attachment {
     /// Optional timestamp to be displayed with the attachment
     #[serde(skip_serializing_if = "Option::is_none")]
     pub ts: Option<SlackTime>,
+    /// Optional Block Kit blocks to render instead of (or alongside) the
+    /// legacy `text`/`fields` attachment model.
+    /// https://api.slack.com/block-kit
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub blocks: Option<Vec<SlackBlock>>,
+    /// Identifier passed back with any interaction callback from `actions`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub callback_id: Option<String>,
+    /// Interactive components, e.g. buttons, shown below the attachment.
+    /// https://api.slack.com/legacy/message-buttons
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub actions: Option<Vec<Action>>,
+    /// Which fields (e.g. `"text"`, `"pretext"`, `"fields"`) should be
+    /// parsed as `mrkdwn` rather than shown as plain text.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mrkdwn_in: Option<Vec<String>>,
+}
+
+/// A single interactive component attached to a message, e.g. a button.
+/// https://api.slack.com/legacy/message-buttons
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Action {
+    /// Name identifying this action within the `callback_id` payload.
+    pub name: String,
+    /// Label shown on the button.
+    pub text: String,
+    /// The kind of interactive component. Only `"button"` is supported by
+    /// legacy message actions, so this defaults to it.
+    #[serde(rename = "type", default = "Action::default_type")]
+    pub action_type: String,
+    /// Value sent back with the interaction callback.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+    /// Visual style of the button.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub style: Option<ActionStyle>,
+    /// URL to open instead of sending an interaction callback.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<Url>,
+    /// Confirmation dialog shown before the action is triggered.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confirm: Option<Confirm>,
+}
+
+impl Action {
+    fn default_type() -> String {
+        "button".to_string()
+    }
+
+    /// Construct a new button-style `Action`.
+    pub fn new<N: Into<String>, T: Into<String>>(name: N, text: T) -> Action {
+        Action {
+            name: name.into(),
+            text: text.into(),
+            action_type: Action::default_type(),
+            value: None,
+            style: None,
+            url: None,
+            confirm: None,
+        }
+    }
+}
+
+/// Visual style of an `Action` button.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "lowercase")]
+pub enum ActionStyle {
+    /// The default, unstyled button.
+    Default,
+    /// A green, primary-styled button.
+    Primary,
+    /// A red, danger-styled button.
+    Danger,
+}
+
+/// Confirmation dialog shown before an `Action` is triggered.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Confirm {
+    /// Title of the confirmation dialog.
+    pub title: String,
+    /// Body text of the confirmation dialog.
+    pub text: String,
+    /// Label for the confirm button.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ok_text: Option<String>,
+    /// Label for the dismiss button.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dismiss_text: Option<String>,
+}
+
+/// Error produced when a `AttachmentBuilder`/`PayloadBuilder` field
+/// conversion fails or a required field was never set.
+#[derive(Debug, Clone)]
+pub enum BuildError {
+    /// A string was not a valid `HexColor`.
+    InvalidColor(HexColorError),
+    /// A string was not a valid `Url`.
+    InvalidUrl(String),
+    /// A required field was never provided to the builder.
+    MissingField(&'static str),
+}
+
+impl fmt::Display for BuildError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            BuildError::InvalidColor(ref e) => write!(f, "{}", e),
+            BuildError::InvalidUrl(ref e) => write!(f, "invalid url: {}", e),
+            BuildError::MissingField(field) => write!(f, "missing required field: {}", field),
+        }
+    }
+}
+
+impl StdError for BuildError {}
+
+impl Attachment {
+    /// Start building an `Attachment` via a fluent builder.
+    pub fn builder() -> AttachmentBuilder {
+        AttachmentBuilder::default()
+    }
+}
+
+/// Fluent builder for `Attachment`, returned by `Attachment::builder()`.
+#[derive(Default)]
+pub struct AttachmentBuilder {
+    fallback: Option<SlackText>,
+    text: Option<SlackText>,
+    pretext: Option<SlackText>,
+    color: Option<HexColor>,
+    fields: Option<Vec<Field>>,
+    author_name: Option<SlackText>,
+    author_link: Option<Url>,
+    author_icon: Option<Url>,
+    title: Option<SlackText>,
+    title_link: Option<Url>,
+    image_url: Option<Url>,
+    thumb_url: Option<Url>,
+    footer: Option<SlackText>,
+    footer_icon: Option<Url>,
+    ts: Option<SlackTime>,
+    blocks: Option<Vec<SlackBlock>>,
+    callback_id: Option<String>,
+    actions: Option<Vec<Action>>,
+    mrkdwn_in: Option<Vec<String>>,
+    error: Option<BuildError>,
+}
+
+impl AttachmentBuilder {
+    /// Required text shown on devices that don't support markup.
+    pub fn fallback<T: Into<SlackText>>(mut self, fallback: T) -> Self {
+        self.fallback = Some(fallback.into());
+        self
+    }
+
+    /// Optional text for other devices, markup supported.
+    pub fn text<T: Into<SlackText>>(mut self, text: T) -> Self {
+        self.text = Some(text.into());
+        self
+    }
+
+    /// Optional text that appears above the attachment.
+    pub fn pretext<T: Into<SlackText>>(mut self, pretext: T) -> Self {
+        self.pretext = Some(pretext.into());
+        self
+    }
+
+    /// Optional color of the attachment, e.g. `"danger"` or `"#b13d41"`.
+    pub fn color<C>(mut self, color: C) -> Self
+        where C: TryInto<HexColor>,
+              C::Error: Into<HexColorError>
+    {
+        match color.try_into() {
+            Ok(c) => self.color = Some(c),
+            Err(e) => if self.error.is_none() {
+                self.error = Some(BuildError::InvalidColor(e.into()));
+            },
+        }
+        self
+    }
+
+    /// Parse a `TryInto<Url>` setter argument, recording the first failure
+    /// (if any) as a `BuildError::InvalidUrl` rather than failing immediately.
+    fn parse_url<U>(&mut self, url: U) -> Option<Url>
+        where U: TryInto<Url>, U::Error: fmt::Display
+    {
+        match url.try_into() {
+            Ok(u) => Some(u),
+            Err(e) => {
+                if self.error.is_none() {
+                    self.error = Some(BuildError::InvalidUrl(e.to_string()));
+                }
+                None
+            }
+        }
+    }
+
+    /// Append a single field to the attachment's fields table.
+    pub fn field(mut self, field: Field) -> Self {
+        self.fields.get_or_insert_with(Vec::new).push(field);
+        self
+    }
+
+    /// Replace the attachment's fields table wholesale.
+    pub fn fields(mut self, fields: Vec<Field>) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Optional small text used to display the author's name.
+    pub fn author_name<T: Into<SlackText>>(mut self, author_name: T) -> Self {
+        self.author_name = Some(author_name.into());
+        self
+    }
+
+    /// Optional URL hyperlinking `author_name`.
+    pub fn author_link<U>(mut self, url: U) -> Self
+        where U: TryInto<Url>, U::Error: fmt::Display
+    {
+        self.author_link = self.parse_url(url);
+        self
+    }
+
+    /// Optional small icon shown next to `author_name`.
+    pub fn author_icon<U>(mut self, url: U) -> Self
+        where U: TryInto<Url>, U::Error: fmt::Display
+    {
+        self.author_icon = self.parse_url(url);
+        self
+    }
+
+    /// Optional larger, bolder text above the main body.
+    pub fn title<T: Into<SlackText>>(mut self, title: T) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Optional URL to link to from the title.
+    pub fn title_link<U>(mut self, url: U) -> Self
+        where U: TryInto<Url>, U::Error: fmt::Display
+    {
+        self.title_link = self.parse_url(url);
+        self
+    }
+
+    /// Optional URL to an image displayed in the body.
+    pub fn image_url<U>(mut self, url: U) -> Self
+        where U: TryInto<Url>, U::Error: fmt::Display
+    {
+        self.image_url = self.parse_url(url);
+        self
+    }
+
+    /// Optional URL to a thumbnail image displayed to the right of the body.
+    pub fn thumb_url<U>(mut self, url: U) -> Self
+        where U: TryInto<Url>, U::Error: fmt::Display
+    {
+        self.thumb_url = self.parse_url(url);
+        self
+    }
+
+    /// Optional text that will appear at the bottom of the attachment.
+    pub fn footer<T: Into<SlackText>>(mut self, footer: T) -> Self {
+        self.footer = Some(footer.into());
+        self
+    }
+
+    /// Optional icon displayed next to the footer text.
+    pub fn footer_icon<U>(mut self, url: U) -> Self
+        where U: TryInto<Url>, U::Error: fmt::Display
+    {
+        self.footer_icon = self.parse_url(url);
+        self
+    }
+
+    /// Optional timestamp displayed with the attachment.
+    pub fn ts(mut self, ts: SlackTime) -> Self {
+        self.ts = Some(ts);
+        self
+    }
+
+    /// Append a single Block Kit block.
+    pub fn block(mut self, block: SlackBlock) -> Self {
+        self.blocks.get_or_insert_with(Vec::new).push(block);
+        self
+    }
+
+    /// Replace the attachment's Block Kit blocks wholesale.
+    pub fn blocks(mut self, blocks: Vec<SlackBlock>) -> Self {
+        self.blocks = Some(blocks);
+        self
+    }
+
+    /// Identifier passed back with any interaction callback from `actions`.
+    pub fn callback_id<S: Into<String>>(mut self, callback_id: S) -> Self {
+        self.callback_id = Some(callback_id.into());
+        self
+    }
+
+    /// Append a single interactive action, e.g. a button.
+    pub fn action(mut self, action: Action) -> Self {
+        self.actions.get_or_insert_with(Vec::new).push(action);
+        self
+    }
+
+    /// Replace the attachment's interactive actions wholesale.
+    pub fn actions(mut self, actions: Vec<Action>) -> Self {
+        self.actions = Some(actions);
+        self
+    }
+
+    /// Which fields (e.g. `"text"`, `"pretext"`, `"fields"`) should be
+    /// parsed as `mrkdwn` rather than shown as plain text.
+    pub fn mrkdwn_in(mut self, mrkdwn_in: Vec<String>) -> Self {
+        self.mrkdwn_in = Some(mrkdwn_in);
+        self
+    }
+
+    /// Build the `Attachment`, failing if a field conversion was invalid or
+    /// `fallback` was never provided.
+    pub fn build(self) -> ::std::result::Result<Attachment, BuildError> {
+        if let Some(err) = self.error {
+            return Err(err);
+        }
+        let fallback = self.fallback.ok_or(BuildError::MissingField("fallback"))?;
+        Ok(Attachment {
+            fallback,
+            text: self.text,
+            pretext: self.pretext,
+            color: self.color,
+            fields: self.fields,
+            author_name: self.author_name,
+            author_link: self.author_link,
+            author_icon: self.author_icon,
+            title: self.title,
+            title_link: self.title_link,
+            image_url: self.image_url,
+            thumb_url: self.thumb_url,
+            footer: self.footer,
+            footer_icon: self.footer_icon,
+            ts: self.ts,
+            blocks: self.blocks,
+            callback_id: self.callback_id,
+            actions: self.actions,
+            mrkdwn_in: self.mrkdwn_in,
+        })
+    }
 }
 
 /// Slack timestamp
@@ -83,16 +531,160 @@ impl SlackTime {
 }
 
 impl Serialize for SlackTime {
-    fn serialize<S>(&self, serializer: &mut S) -> ::std::result::Result<(), S::Error>
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
         where S: Serializer
     {
         serializer.serialize_i64(self.0.timestamp())
     }
 }
 
+impl<'de> Deserialize<'de> for SlackTime {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<Self, D::Error>
+        where D: Deserializer<'de>
+    {
+        let timestamp = i64::deserialize(deserializer)?;
+        match NaiveDateTime::from_timestamp_opt(timestamp, 0) {
+            Some(time) => Ok(SlackTime(time)),
+            None => Err(DeError::custom(format!("out-of-range timestamp: {}", timestamp))),
+        }
+    }
+}
+
+/// A block of content as used by Slack's Block Kit.
+/// https://api.slack.com/block-kit
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum SlackBlock {
+    /// A section is the most common block, holding a text object plus an
+    /// optional list of short fields and an optional accessory element.
+    Section {
+        /// The text shown in the section.
+        text: SlackBlockText,
+        /// Optional list of short text objects rendered as a table.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        fields: Option<Vec<SlackBlockText>>,
+        /// Optional element (e.g. a button or image) attached to the side
+        /// of the section.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        accessory: Option<SlackBlockElement>,
+    },
+    /// A simple visual divider between blocks.
+    Divider,
+    /// An image block, with an optional title.
+    Image {
+        /// URL of the image to display.
+        image_url: Url,
+        /// Plain-text summary shown for accessibility.
+        alt_text: String,
+        /// Optional title shown above the image.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        title: Option<SlackBlockText>,
+    },
+    /// Supplementary information shown in a smaller font alongside icons.
+    Context {
+        /// Text or image elements to display.
+        elements: Vec<SlackContextElement>,
+    },
+    /// A larger header above a group of blocks.
+    Header {
+        /// The plain text shown in the header.
+        text: SlackBlockText,
+    },
+    /// A block of interactive elements, e.g. buttons.
+    Actions {
+        /// The interactive elements to display.
+        elements: Vec<SlackBlockElement>,
+    },
+}
+
+/// Slack's text object, used throughout Block Kit blocks.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum SlackBlockText {
+    /// Plain, unformatted text.
+    PlainText {
+        /// The text to display.
+        text: String,
+        /// Whether emoji shortcodes (e.g. `:+1:`) should be rendered.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        emoji: Option<bool>,
+    },
+    /// Text using Slack's `mrkdwn` markup.
+    Mrkdwn {
+        /// The text to display.
+        text: String,
+        /// When `true`, disables markup processing of the text, e.g. links.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        verbatim: Option<bool>,
+    },
+}
+
+/// An element that can appear inside a `SlackBlock::Context`, alongside
+/// small images. Unlike `SlackBlockText` this additionally admits images,
+/// since a context block mixes captions and thumbnails.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum SlackContextElement {
+    /// Plain, unformatted text.
+    PlainText {
+        /// The text to display.
+        text: String,
+        /// Whether emoji shortcodes (e.g. `:+1:`) should be rendered.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        emoji: Option<bool>,
+    },
+    /// Text using Slack's `mrkdwn` markup.
+    Mrkdwn {
+        /// The text to display.
+        text: String,
+        /// When `true`, disables markup processing of the text, e.g. links.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        verbatim: Option<bool>,
+    },
+    /// A small image shown alongside the context's text.
+    Image {
+        /// URL of the image to display.
+        image_url: Url,
+        /// Plain-text summary shown for accessibility.
+        alt_text: String,
+    },
+}
+
+/// An interactive or decorative element that can appear as a
+/// `SlackBlock::Section` accessory or inside a `SlackBlock::Actions` list.
+/// Unlike `SlackBlock` itself, this cannot hold another full block, so it
+/// can't be nested into an invalid Slack payload.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum SlackBlockElement {
+    /// A clickable button.
+    Button {
+        /// The label shown on the button.
+        text: SlackBlockText,
+        /// Identifier sent back with the interaction payload.
+        action_id: String,
+        /// Value sent back with the interaction payload.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        value: Option<String>,
+        /// URL to open instead of sending an interaction payload.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        url: Option<Url>,
+        /// Visual style of the button.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        style: Option<ActionStyle>,
+    },
+    /// A small image.
+    Image {
+        /// URL of the image to display.
+        image_url: Url,
+        /// Plain-text summary shown for accessibility.
+        alt_text: String,
+    },
+}
+
 /// Fields are defined as an array, and hashes contained within it will
 /// be displayed in a table inside the message attachment.
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Deserialize, Debug)]
 pub struct Field {
     /// Shown as a bold heading above the value text.
     /// It cannot contain markup and will be escaped for you.
@@ -109,7 +701,7 @@ pub struct Field {
 /// Payload to send to slack
 /// https://api.slack.com/incoming-webhooks
 /// https://api.slack.com/methods/chat.postMessage
-#[derive(Serialize, Debug, Default)]
+#[derive(Serialize, Deserialize, Debug, Default)]
 pub struct Payload {
     /// text to send
     /// despite `text` stated as required, it does not seem to be
@@ -146,6 +738,141 @@ pub struct Payload {
     /// Change how messages are treated.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub parse: Option<Parse>,
+    /// Optional Block Kit blocks to render instead of (or alongside) `text`.
+    /// https://api.slack.com/block-kit
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub blocks: Option<Vec<SlackBlock>>,
+}
+
+impl Payload {
+    /// Start building a `Payload` via a fluent builder.
+    pub fn builder() -> PayloadBuilder {
+        PayloadBuilder::default()
+    }
+}
+
+/// Fluent builder for `Payload`, returned by `Payload::builder()`.
+#[derive(Default)]
+pub struct PayloadBuilder {
+    text: Option<SlackText>,
+    channel: Option<String>,
+    username: Option<String>,
+    icon_url: Option<Url>,
+    icon_emoji: Option<String>,
+    attachments: Option<Vec<Attachment>>,
+    unfurl_links: Option<bool>,
+    unfurl_media: Option<bool>,
+    link_names: Option<u8>,
+    parse: Option<Parse>,
+    blocks: Option<Vec<SlackBlock>>,
+    error: Option<BuildError>,
+}
+
+impl PayloadBuilder {
+    /// Text to send.
+    pub fn text<T: Into<SlackText>>(mut self, text: T) -> Self {
+        self.text = Some(text.into());
+        self
+    }
+
+    /// Channel to send the payload to.
+    pub fn channel<S: Into<String>>(mut self, channel: S) -> Self {
+        self.channel = Some(channel.into());
+        self
+    }
+
+    /// Username override.
+    pub fn username<S: Into<String>>(mut self, username: S) -> Self {
+        self.username = Some(username.into());
+        self
+    }
+
+    /// Specific URL to use as the icon.
+    pub fn icon_url<U>(mut self, url: U) -> Self
+        where U: TryInto<Url>, U::Error: fmt::Display
+    {
+        match url.try_into() {
+            Ok(u) => self.icon_url = Some(u),
+            Err(e) => if self.error.is_none() {
+                self.error = Some(BuildError::InvalidUrl(e.to_string()));
+            },
+        }
+        self
+    }
+
+    /// Emoji to use as the icon, e.g. `":ghost:"`.
+    pub fn icon_emoji<S: Into<String>>(mut self, icon_emoji: S) -> Self {
+        self.icon_emoji = Some(icon_emoji.into());
+        self
+    }
+
+    /// Append a single attachment.
+    pub fn attachment(mut self, attachment: Attachment) -> Self {
+        self.attachments.get_or_insert_with(Vec::new).push(attachment);
+        self
+    }
+
+    /// Replace the payload's attachments wholesale.
+    pub fn attachments(mut self, attachments: Vec<Attachment>) -> Self {
+        self.attachments = Some(attachments);
+        self
+    }
+
+    /// Whether Slack should try to fetch links and create an attachment.
+    pub fn unfurl_links(mut self, unfurl_links: bool) -> Self {
+        self.unfurl_links = Some(unfurl_links);
+        self
+    }
+
+    /// Pass `false` to disable unfurling of media content.
+    pub fn unfurl_media(mut self, unfurl_media: bool) -> Self {
+        self.unfurl_media = Some(unfurl_media);
+        self
+    }
+
+    /// Find and link channel names and usernames.
+    pub fn link_names(mut self, link_names: u8) -> Self {
+        self.link_names = Some(link_names);
+        self
+    }
+
+    /// Change how messages are treated.
+    pub fn parse(mut self, parse: Parse) -> Self {
+        self.parse = Some(parse);
+        self
+    }
+
+    /// Append a single Block Kit block.
+    pub fn block(mut self, block: SlackBlock) -> Self {
+        self.blocks.get_or_insert_with(Vec::new).push(block);
+        self
+    }
+
+    /// Replace the payload's Block Kit blocks wholesale.
+    pub fn blocks(mut self, blocks: Vec<SlackBlock>) -> Self {
+        self.blocks = Some(blocks);
+        self
+    }
+
+    /// Build the `Payload`, failing if `icon_url` was given an invalid URL.
+    pub fn build(self) -> ::std::result::Result<Payload, BuildError> {
+        if let Some(err) = self.error {
+            return Err(err);
+        }
+        Ok(Payload {
+            text: self.text,
+            channel: self.channel,
+            username: self.username,
+            icon_url: self.icon_url,
+            icon_emoji: self.icon_emoji,
+            attachments: self.attachments,
+            unfurl_links: self.unfurl_links,
+            unfurl_media: self.unfurl_media,
+            link_names: self.link_names,
+            parse: self.parse,
+            blocks: self.blocks,
+        })
+    }
 }
 
 /// Change how messages are treated.
@@ -158,7 +885,7 @@ pub enum Parse {
 }
 
 impl Serialize for Parse {
-    fn serialize<S>(&self, serializer: &mut S) -> ::std::result::Result<(), S::Error>
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
         where S: Serializer
     {
         let st = match *self {
@@ -168,3 +895,225 @@ impl Serialize for Parse {
         serializer.serialize_str(st)
     }
 }
+
+impl<'de> Deserialize<'de> for Parse {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<Self, D::Error>
+        where D: Deserializer<'de>
+    {
+        let s = String::deserialize(deserializer)?;
+        match s.as_str() {
+            "full" => Ok(Parse::Full),
+            "none" => Ok(Parse::None),
+            other => Err(DeError::custom(format!("unknown parse mode: {}", other))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hex_color_accepts_keywords_and_hex_codes() {
+        assert!(HexColor::try_from("danger").is_ok());
+        assert!(HexColor::try_from("#b13d41").is_ok());
+        assert!(HexColor::try_from("#fff").is_ok());
+    }
+
+    #[test]
+    fn hex_color_deserialize_rejects_invalid_input() {
+        let result: ::std::result::Result<HexColor, _> = serde_json::from_str("\"not-a-color\"");
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn parse_round_trips_through_json() {
+        let json = serde_json::to_string(&Parse::Full).unwrap();
+        assert_eq!(json, "\"full\"");
+        let parsed: Parse = serde_json::from_str(&json).unwrap();
+        assert!(matches!(parsed, Parse::Full));
+    }
+
+    #[test]
+    fn parse_deserialize_rejects_unknown_string() {
+        let result: ::std::result::Result<Parse, _> = serde_json::from_str("\"sometimes\"");
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn slack_time_round_trips_through_json() {
+        let time = NaiveDateTime::parse_from_str("2020-01-01 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+        let st = SlackTime::new(&time);
+        let json = serde_json::to_string(&st).unwrap();
+        let parsed: SlackTime = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed.0.timestamp(), time.timestamp());
+    }
+
+    #[test]
+    fn slack_time_deserialize_rejects_out_of_range_timestamp_instead_of_panicking() {
+        let result: ::std::result::Result<SlackTime, _> = serde_json::from_str("9223372036854775807");
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn section_block_round_trips_with_button_accessory() {
+        let block = SlackBlock::Section {
+            text: SlackBlockText::Mrkdwn { text: "hi".into(), verbatim: None },
+            fields: None,
+            accessory: Some(SlackBlockElement::Button {
+                text: SlackBlockText::PlainText { text: "Click".into(), emoji: None },
+                action_id: "click".into(),
+                value: None,
+                url: None,
+                style: None,
+            }),
+        };
+        let json = serde_json::to_string(&block).unwrap();
+        assert!(json.contains("\"type\":\"section\""));
+        assert!(json.contains("\"type\":\"button\""));
+        let parsed: SlackBlock = serde_json::from_str(&json).unwrap();
+        assert!(matches!(parsed, SlackBlock::Section { .. }));
+    }
+
+    #[test]
+    fn context_block_admits_image_elements() {
+        let block = SlackBlock::Context {
+            elements: vec![
+                SlackContextElement::PlainText { text: "caption".into(), emoji: None },
+                SlackContextElement::Image { image_url: Url::parse("https://example.com/x.png").unwrap(), alt_text: "x".into() },
+            ],
+        };
+        let json = serde_json::to_string(&block).unwrap();
+        let parsed: SlackBlock = serde_json::from_str(&json).unwrap();
+        match parsed {
+            SlackBlock::Context { elements } => assert_eq!(elements.len(), 2),
+            _ => panic!("expected a context block"),
+        }
+    }
+
+    #[test]
+    fn attachment_builder_requires_fallback() {
+        let err = Attachment::builder().build().unwrap_err();
+        assert!(matches!(err, BuildError::MissingField("fallback")));
+    }
+
+    #[test]
+    fn attachment_builder_rejects_invalid_color() {
+        let err = Attachment::builder()
+            .fallback("hi")
+            .color("not-a-color")
+            .build()
+            .unwrap_err();
+        assert!(matches!(err, BuildError::InvalidColor(_)));
+    }
+
+    #[test]
+    fn attachment_builder_accepts_a_pre_validated_hex_color() {
+        let color = HexColor::try_from("danger").unwrap();
+        let attachment = Attachment::builder()
+            .fallback("hi")
+            .color(color)
+            .build()
+            .unwrap();
+        assert!(attachment.color.is_some());
+    }
+
+    #[test]
+    fn attachment_builder_rejects_invalid_url_string() {
+        let err = Attachment::builder()
+            .fallback("hi")
+            .image_url("not a url")
+            .build()
+            .unwrap_err();
+        assert!(matches!(err, BuildError::InvalidUrl(_)));
+    }
+
+    #[test]
+    fn attachment_builder_accepts_url_strings_and_url_values() {
+        let attachment = Attachment::builder()
+            .fallback("hi")
+            .image_url("https://example.com/x.png")
+            .thumb_url(Url::parse("https://example.com/y.png").unwrap())
+            .build()
+            .unwrap();
+        assert!(attachment.image_url.is_some());
+        assert!(attachment.thumb_url.is_some());
+    }
+
+    #[test]
+    fn payload_builder_rejects_invalid_icon_url() {
+        let err = Payload::builder().icon_url("not a url").build().unwrap_err();
+        assert!(matches!(err, BuildError::InvalidUrl(_)));
+    }
+
+    #[test]
+    fn payload_builder_builds_with_no_fields_set() {
+        let payload = Payload::builder().build().unwrap();
+        assert!(payload.text.is_none());
+    }
+
+    #[test]
+    fn action_new_defaults_to_button_type_and_round_trips() {
+        let action = Action::new("approve", "Approve");
+        let json = serde_json::to_string(&action).unwrap();
+        assert!(json.contains("\"type\":\"button\""));
+        let parsed: Action = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed.name, "approve");
+        assert_eq!(parsed.action_type, "button");
+    }
+
+    #[test]
+    fn action_type_defaults_when_absent_from_json() {
+        let json = r#"{"name":"approve","text":"Approve"}"#;
+        let parsed: Action = serde_json::from_str(json).unwrap();
+        assert_eq!(parsed.action_type, "button");
+    }
+
+    #[test]
+    fn action_style_serializes_as_lowercase() {
+        assert_eq!(serde_json::to_string(&ActionStyle::Primary).unwrap(), "\"primary\"");
+        assert_eq!(serde_json::to_string(&ActionStyle::Danger).unwrap(), "\"danger\"");
+    }
+
+    #[test]
+    fn attachment_builder_carries_callback_id_and_actions() {
+        let mut action = Action::new("approve", "Approve");
+        action.confirm = Some(Confirm {
+            title: "Sure?".into(),
+            text: "This can't be undone.".into(),
+            ok_text: None,
+            dismiss_text: None,
+        });
+        let attachment = Attachment::builder()
+            .fallback("hi")
+            .callback_id("my_callback")
+            .action(action)
+            .build()
+            .unwrap();
+        assert_eq!(attachment.callback_id.as_deref(), Some("my_callback"));
+        assert_eq!(attachment.actions.unwrap().len(), 1);
+    }
+
+    #[test]
+    fn slack_text_new_unescaped_preserves_mrkdwn_markup() {
+        let raw = SlackText::new_unescaped("*bold* <https://example.com|label> & stuff");
+        assert_eq!(
+            serde_json::to_string(&raw).unwrap(),
+            "\"*bold* <https://example.com|label> & stuff\""
+        );
+    }
+
+    #[test]
+    fn attachment_builder_carries_mrkdwn_in() {
+        let attachment = Attachment::builder()
+            .fallback("hi")
+            .text(SlackText::new_unescaped("*bold*"))
+            .mrkdwn_in(vec!["text".to_string(), "pretext".to_string()])
+            .build()
+            .unwrap();
+        assert_eq!(
+            attachment.mrkdwn_in,
+            Some(vec!["text".to_string(), "pretext".to_string()])
+        );
+    }
+}